@@ -13,6 +13,51 @@ pub struct AffinePoint<F: Field> {
     pub infinity: bool,
 }
 
+/// A point in Jacobian projective coordinates `(X, Y, Z)` representing the
+/// affine point `(X/Z², Y/Z³)`. The point at infinity is any triple with
+/// `Z == 0`. Working in these coordinates lets the group law run with only
+/// field multiplications and squarings, deferring the single modular
+/// inversion until a point is converted back to affine form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectivePoint<F: Field> {
+    pub x: F,
+    pub y: F,
+    pub z: F,
+}
+
+impl<F: Field> ProjectivePoint<F> {
+    /// Returns the identity element (`Z == 0`).
+    pub fn identity() -> Self { Self { x: F::one(), y: F::one(), z: F::zero() } }
+
+    /// Returns true if this point is the identity.
+    pub fn is_identity(&self) -> bool { self.z.is_zero() }
+
+    /// Embeds an affine point into Jacobian coordinates with `Z = 1`.
+    pub fn from_affine(p: &AffinePoint<F>) -> Self {
+        if p.infinity {
+            return Self::identity();
+        }
+        Self { x: p.x.clone(), y: p.y.clone(), z: F::one() }
+    }
+
+    /// Normalizes back to affine coordinates, performing the single modular
+    /// inversion of `Z`. Returns the point at infinity when `Z == 0`.
+    pub fn to_affine(&self) -> AffinePoint<F> {
+        match self.z.inverse() {
+            None => AffinePoint { x: F::zero(), y: F::zero(), infinity: true },
+            Some(z_inv) => {
+                let z_inv2 = z_inv.square();
+                let z_inv3 = z_inv2.clone() * z_inv;
+                AffinePoint {
+                    x:        self.x.clone() * z_inv2,
+                    y:        self.y.clone() * z_inv3,
+                    infinity: false,
+                }
+            },
+        }
+    }
+}
+
 /// Basic operations required for an elliptic curve
 pub trait EllipticCurve: Sized + Clone + Debug + PartialEq {
     /// The field over which this curve is defined
@@ -21,6 +66,10 @@ pub trait EllipticCurve: Sized + Clone + Debug + PartialEq {
     /// The type representing a point on this curve
     type Point: Clone + Debug + PartialEq;
 
+    /// The type representing a point in projective coordinates, used to
+    /// accumulate results without a per-operation modular inversion.
+    type ProjectivePoint: Clone + Debug + PartialEq;
+
     /// Returns the identity element (point at infinity)
     fn identity() -> Self::Point;
 
@@ -36,6 +85,11 @@ pub trait EllipticCurve: Sized + Clone + Debug + PartialEq {
     /// Multiplies a point by a scalar
     fn scalar_mul(point: &Self::Point, scalar: &[u64]) -> Self::Point;
 
+    /// Computes the multi-scalar multiplication `Σ scalarᵢ·basesᵢ`. The
+    /// `Curve25519` implementation uses the Pippenger bucket method; the
+    /// example pairing curve sums one `scalar_mul` per base.
+    fn msm(bases: &[Self::Point], scalars: &[&[u64]]) -> Self::Point;
+
     /// Checks if a point is on the curve
     fn is_on_curve(point: &Self::Point) -> bool;
 }
@@ -57,3 +111,47 @@ pub trait PairingCurve: EllipticCurve {
     /// Compute the pairing of two points
     fn pairing(p: &Self::Point, q: &Self::Point) -> Self::TargetField;
 }
+
+/// A pairing engine tying together the two source groups `G1`, `G2`, the
+/// scalar field `Fr`, and the target field `Fqk` living in a tower of
+/// extension fields. This mirrors the `Engine` abstraction used by the
+/// `pairing`/librustzcash crates and provides the two halves of a pairing
+/// computation: the Miller loop and the final exponentiation.
+pub trait Engine {
+    /// The first source group.
+    type G1: EllipticCurve;
+
+    /// The second source group, defined over an extension of the base field.
+    type G2: EllipticCurve;
+
+    /// The scalar field shared by both groups.
+    type Fr: Field;
+
+    /// The target group `Fqk`, an extension-field tower element.
+    type Fqk: Field;
+
+    /// Accumulates the line-function evaluations over the bits of the loop
+    /// parameter, returning the unreduced Miller value `f`.
+    fn miller_loop<'a, I>(pairs: I) -> Self::Fqk
+    where
+        I: IntoIterator<
+            Item = (
+                &'a <Self::G1 as EllipticCurve>::Point,
+                &'a <Self::G2 as EllipticCurve>::Point,
+            ),
+        >,
+        <Self::G1 as EllipticCurve>::Point: 'a,
+        <Self::G2 as EllipticCurve>::Point: 'a;
+
+    /// Raises the Miller value to `(qᵏ − 1)/r`, mapping it into the group of
+    /// `r`-th roots of unity. Returns `None` when the input is not invertible.
+    fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk>;
+
+    /// The full pairing: a Miller loop followed by the final exponentiation.
+    fn pairing(
+        p: &<Self::G1 as EllipticCurve>::Point,
+        q: &<Self::G2 as EllipticCurve>::Point,
+    ) -> Option<Self::Fqk> {
+        Self::final_exponentiation(&Self::miller_loop(core::iter::once((p, q))))
+    }
+}
@@ -4,8 +4,8 @@ use core::{
 };
 
 use crate::{
-    ec::{AffinePoint, EllipticCurve},
-    field::{Field, SquareRootField},
+    ec::{AffinePoint, EllipticCurve, ProjectivePoint},
+    field::{Field, FromBytes, PrimeField, SquareRootField, ToBytes},
 };
 
 // Constants for Curve25519
@@ -13,6 +13,30 @@ const CURVE_A: [u64; 4] = [486662, 0, 0, 0]; // Curve parameter A
 const PRIME_MODULUS: [u64; 4] =
     [0xFFFFFFFFFFFFFFED, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0x7FFFFFFFFFFFFFFF]; // 2^255 - 19
 
+// (p + 3) / 8 = 2^252 - 2, the exponent of Atkin's square-root shortcut for
+// primes p ≡ 5 (mod 8).
+const SQRT_EXP: [u64; 4] =
+    [0xFFFFFFFFFFFFFFFE, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0x0FFFFFFFFFFFFFFF];
+
+// (p − 1) / 2 = 2^254 − 10, the Euler-criterion exponent for the Legendre
+// symbol. It does not fit in a single limb, so it is applied via `pow_limbs`.
+const LEGENDRE_EXP: [u64; 4] =
+    [0xFFFFFFFFFFFFFFF6, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0x3FFFFFFFFFFFFFFF];
+
+// p − 2, the Fermat inverse exponent a^(p−2) ≡ a⁻¹ (mod p). Like the Legendre
+// exponent it spans all four limbs and must be applied via `pow_limbs`.
+const INVERSE_EXP: [u64; 4] =
+    [0xFFFFFFFFFFFFFFEB, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0x7FFFFFFFFFFFFFFF];
+
+// sqrt(-1) mod p = 2^((p-1)/4), used to fix up the candidate root when the
+// fast path lands on the wrong coset.
+const SQRT_MINUS_ONE: [u64; 4] = [
+    0xc4ee1b274a0ea0b0,
+    0x2f431806ad2fe478,
+    0x2b4d00993dfbd7a7,
+    0x2b8324804fc1df0b,
+];
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Fp25519 {
     value: [u64; 4],
@@ -20,28 +44,200 @@ pub struct Fp25519 {
 
 impl Fp25519 {
     pub fn new(value: [u64; 4]) -> Self {
-        let mut result = Self { value };
-        result.reduce();
+        Self { value: reduce_wide([value[0], value[1], value[2], value[3], 0, 0, 0, 0]) }
+    }
+
+    /// Exponentiates by a full multi-limb exponent (little-endian limbs),
+    /// which `pow`'s `u64` exponent cannot express for moduli this size.
+    pub fn pow_limbs(&self, exp: &[u64]) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::one();
+        for &limb in exp {
+            let mut e = limb;
+            for _ in 0..64 {
+                if e & 1 == 1 {
+                    result = result * base.clone();
+                }
+                base = base.square();
+                e >>= 1;
+            }
+        }
         result
     }
+}
+
+// Little-endian multi-limb helpers over the 4-limb modulus, used by the
+// general Tonelli–Shanks fallback.
+fn limbs_sub_one(mut a: [u64; 4]) -> [u64; 4] {
+    let mut i = 0;
+    while i < 4 {
+        if a[i] == 0 {
+            a[i] = u64::MAX;
+            i += 1;
+        } else {
+            a[i] -= 1;
+            break;
+        }
+    }
+    a
+}
+
+fn limbs_add_one(mut a: [u64; 4]) -> [u64; 4] {
+    let mut i = 0;
+    while i < 4 {
+        let (v, carry) = a[i].overflowing_add(1);
+        a[i] = v;
+        if !carry {
+            break;
+        }
+        i += 1;
+    }
+    a
+}
+
+fn limbs_trailing_zeros(a: [u64; 4]) -> u32 {
+    let mut tz = 0;
+    for limb in a {
+        if limb == 0 {
+            tz += 64;
+        } else {
+            return tz + limb.trailing_zeros();
+        }
+    }
+    tz
+}
+
+fn limbs_shr(mut a: [u64; 4], n: u32) -> [u64; 4] {
+    for _ in 0..n {
+        let mut carry = 0u64;
+        for i in (0..4).rev() {
+            let next = a[i] & 1;
+            a[i] = (a[i] >> 1) | (carry << 63);
+            carry = next;
+        }
+    }
+    a
+}
 
-    // Reduces the value modulo p
-    fn reduce(&mut self) {
-        let mut carry: u128 = 0;
+// True if the 4-limb value is greater than or equal to the modulus.
+fn limbs_ge_modulus(r: [u64; 4]) -> bool {
+    for i in (0..4).rev() {
+        if r[i] > PRIME_MODULUS[i] {
+            return true;
+        }
+        if r[i] < PRIME_MODULUS[i] {
+            return false;
+        }
+    }
+    true
+}
+
+// Subtracts the modulus from a 4-limb value, assuming it is >= p.
+fn limbs_sub_modulus(r: [u64; 4]) -> [u64; 4] {
+    let mut out = [0u64; 4];
+    let mut borrow = 0i128;
+    for i in 0..4 {
+        let v = r[i] as i128 - PRIME_MODULUS[i] as i128 - borrow;
+        if v < 0 {
+            out[i] = (v + (1i128 << 64)) as u64;
+            borrow = 1;
+        } else {
+            out[i] = v as u64;
+            borrow = 0;
+        }
+    }
+    out
+}
+
+// Reduces a 512-bit little-endian product modulo p = 2^255 − 19. The high half
+// is folded into the low half using 2^256 ≡ 38 (mod p) until it vanishes, then
+// the modulus is subtracted until the result is the canonical residue.
+fn reduce_wide(mut t: [u64; 8]) -> [u64; 4] {
+    loop {
+        let hi = [t[4], t[5], t[6], t[7]];
+        if hi == [0u64; 4] {
+            break;
+        }
+        t[4] = 0;
+        t[5] = 0;
+        t[6] = 0;
+        t[7] = 0;
+        let mut carry = 0u128;
         for i in 0..4 {
-            let mut acc = self.value[i] as u128 + carry;
-            if acc >= PRIME_MODULUS[i] as u128 {
-                acc -= PRIME_MODULUS[i] as u128;
-                carry = 1;
-            } else {
-                carry = 0;
-            }
-            self.value[i] = acc as u64;
+            let v = t[i] as u128 + 38u128 * hi[i] as u128 + carry;
+            t[i] = v as u64;
+            carry = v >> 64;
         }
-        if carry > 0 {
-            self.reduce();
+        let mut k = 4;
+        while carry > 0 {
+            let v = t[k] as u128 + carry;
+            t[k] = v as u64;
+            carry = v >> 64;
+            k += 1;
         }
     }
+
+    let mut r = [t[0], t[1], t[2], t[3]];
+    while limbs_ge_modulus(r) {
+        r = limbs_sub_modulus(r);
+    }
+    r
+}
+
+impl Fp25519 {
+    /// General Tonelli–Shanks square root for an arbitrary odd prime modulus,
+    /// kept as a fallback for primes that do not admit Atkin's `p ≡ 5 (mod 8)`
+    /// shortcut. Writes `p − 1 = q·2^s` with `q` odd, picks a quadratic
+    /// non-residue `z`, then iteratively shrinks the 2-power order of `t`.
+    pub fn tonelli_shanks(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+        if self.legendre() != 1 {
+            return None;
+        }
+
+        let p_minus_1 = limbs_sub_one(PRIME_MODULUS);
+        let s = limbs_trailing_zeros(p_minus_1);
+        let q = limbs_shr(p_minus_1, s);
+
+        // Find a quadratic non-residue to seed the 2-Sylow generator.
+        let mut z = Self::new([2, 0, 0, 0]);
+        while z.legendre() != -1 {
+            z = z + Self::one();
+        }
+
+        let mut m = s;
+        let mut c = z.pow_limbs(&q);
+        let mut t = self.pow_limbs(&q);
+        let mut r = self.pow_limbs(&limbs_shr(limbs_add_one(q), 1));
+
+        while t != Self::one() {
+            // Least i in (0, m) such that t^(2^i) == 1.
+            let mut i = 0u32;
+            let mut t2 = t.clone();
+            while t2 != Self::one() {
+                t2 = t2.square();
+                i += 1;
+                if i == m {
+                    return None;
+                }
+            }
+
+            // b = c^(2^(m − i − 1)).
+            let mut b = c.clone();
+            for _ in 0..(m - i - 1) {
+                b = b.square();
+            }
+
+            r = r * b.clone();
+            c = b.square();
+            t = t * c.clone();
+            m = i;
+        }
+
+        Some(r)
+    }
 }
 
 // Field trait implementations for Fp25519
@@ -59,8 +255,9 @@ impl Field for Fp25519 {
             None
         } else {
             // Fermat's little theorem: a^(p-1) ≡ 1 (mod p)
-            // Therefore, a^(p-2) is the multiplicative inverse
-            Some(self.pow(PRIME_MODULUS[0].wrapping_sub(2)))
+            // Therefore, a^(p-2) is the multiplicative inverse. The exponent
+            // spans all four limbs, so it is applied via `pow_limbs`.
+            Some(self.pow_limbs(&INVERSE_EXP))
         }
     }
 
@@ -80,45 +277,81 @@ impl Field for Fp25519 {
     }
 }
 
+impl PrimeField for Fp25519 {
+    fn modulus() -> Vec<u64> { PRIME_MODULUS.to_vec() }
+
+    fn from_u64(n: u64) -> Self { Self::new([n, 0, 0, 0]) }
+
+    fn to_bits(&self) -> Vec<bool> {
+        // Little-endian over the 255 bits of the field modulus.
+        (0..255).map(|i| (self.value[i / 64] >> (i % 64)) & 1 == 1).collect()
+    }
+
+    fn from_bits(bits: &[bool]) -> Option<Self> {
+        if bits.len() > 255 {
+            return None;
+        }
+        let mut value = [0u64; 4];
+        for (i, bit) in bits.iter().enumerate() {
+            if *bit {
+                value[i / 64] |= 1 << (i % 64);
+            }
+        }
+        // Reject non-canonical bit patterns (≥ p).
+        if !limbs_lt_modulus(value) {
+            return None;
+        }
+        Some(Self { value })
+    }
+}
+
 // Basic arithmetic implementations for Fp25519
 impl Add for Fp25519 {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
-        let mut result = self.clone();
-        result.value.iter_mut().zip(other.value.iter()).for_each(|(a, b)| *a = a.wrapping_add(*b));
-        result.reduce();
-        result
+        let mut t = [0u64; 8];
+        let mut carry = 0u128;
+        for i in 0..4 {
+            let v = self.value[i] as u128 + other.value[i] as u128 + carry;
+            t[i] = v as u64;
+            carry = v >> 64;
+        }
+        t[4] = carry as u64;
+        Self { value: reduce_wide(t) }
     }
 }
 
 impl Sub for Fp25519 {
     type Output = Self;
 
-    fn sub(self, other: Self) -> Self {
-        let mut result = self.clone();
-        result.value.iter_mut().zip(other.value.iter()).for_each(|(a, b)| *a = a.wrapping_sub(*b));
-        result.reduce();
-        result
-    }
+    fn sub(self, other: Self) -> Self { self + (-other) }
 }
 
 impl Mul for Fp25519 {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
-        let mut result = [0u64; 4];
+        // Full 256×256 → 512-bit schoolbook product, reduced modulo p.
+        let mut t = [0u64; 8];
         for i in 0..4 {
             let mut carry = 0u128;
             for j in 0..4 {
-                if i + j < 4 {
-                    let prod = (self.value[i] as u128) * (other.value[j] as u128) + carry;
-                    result[i + j] = result[i + j].wrapping_add(prod as u64);
-                    carry = prod >> 64;
-                }
+                let v = t[i + j] as u128
+                    + (self.value[i] as u128) * (other.value[j] as u128)
+                    + carry;
+                t[i + j] = v as u64;
+                carry = v >> 64;
+            }
+            let mut k = i + 4;
+            while carry > 0 {
+                let v = t[k] as u128 + carry;
+                t[k] = v as u64;
+                carry = v >> 64;
+                k += 1;
             }
         }
-        Self::new(result)
+        Self { value: reduce_wide(t) }
     }
 }
 
@@ -129,17 +362,28 @@ impl Neg for Fp25519 {
         if self.is_zero() {
             return self;
         }
-        let mut result = Self::zero();
-        result
-            .value
-            .iter_mut()
-            .zip(self.value.iter())
-            .for_each(|(a, b)| *a = PRIME_MODULUS[0].wrapping_sub(*b));
-        result.reduce();
-        result
+        // p − value, which is < p since a nonzero value is canonical (< p).
+        Self { value: limbs_sub_modulus_from(self.value) }
     }
 }
 
+// Computes p − value for a canonical value in (0, p).
+fn limbs_sub_modulus_from(v: [u64; 4]) -> [u64; 4] {
+    let mut out = [0u64; 4];
+    let mut borrow = 0i128;
+    for i in 0..4 {
+        let x = PRIME_MODULUS[i] as i128 - v[i] as i128 - borrow;
+        if x < 0 {
+            out[i] = (x + (1i128 << 64)) as u64;
+            borrow = 1;
+        } else {
+            out[i] = x as u64;
+            borrow = 0;
+        }
+    }
+    out
+}
+
 // Implement assignment operators
 impl AddAssign for Fp25519 {
     fn add_assign(&mut self, other: Self) { *self = self.clone() + other; }
@@ -164,18 +408,125 @@ impl Display for Fp25519 {
     }
 }
 
+// Returns true if the little-endian limbs are a canonical residue (< p).
+fn limbs_lt_modulus(v: [u64; 4]) -> bool {
+    for i in (0..4).rev() {
+        if v[i] < PRIME_MODULUS[i] {
+            return true;
+        }
+        if v[i] > PRIME_MODULUS[i] {
+            return false;
+        }
+    }
+    false
+}
+
+// Fixed 32-byte little-endian encoding matching the `[u64; 4]` limbs.
+impl ToBytes for Fp25519 {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(32);
+        for limb in self.value {
+            out.extend_from_slice(&limb.to_le_bytes());
+        }
+        out
+    }
+}
+
+impl FromBytes for Fp25519 {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 32 {
+            return None;
+        }
+        let mut value = [0u64; 4];
+        for (i, limb) in value.iter_mut().enumerate() {
+            *limb = u64::from_le_bytes(bytes[i * 8..i * 8 + 8].try_into().unwrap());
+        }
+        // Reject non-canonical encodings (≥ p).
+        if !limbs_lt_modulus(value) {
+            return None;
+        }
+        Some(Self { value })
+    }
+}
+
+// Compressed point encoding: the 32-byte x-coordinate with the top bit of the
+// final byte carrying the parity of y. The point at infinity uses the reserved
+// all-ones encoding, which can never be a finite point (its x-coordinate would
+// be non-canonical), so genuine points such as the 2-torsion point (0, 0) round
+// trip unambiguously.
+const COMPRESSED_INFINITY: [u8; 32] = [0xFF; 32];
+
+impl ToBytes for AffinePoint<Fp25519> {
+    fn to_bytes(&self) -> Vec<u8> {
+        if self.infinity {
+            return COMPRESSED_INFINITY.to_vec();
+        }
+        let mut out = self.x.to_bytes();
+        if self.y.value[0] & 1 == 1 {
+            out[31] |= 0x80;
+        }
+        out
+    }
+}
+
+impl FromBytes for AffinePoint<Fp25519> {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 32 {
+            return None;
+        }
+        if bytes == COMPRESSED_INFINITY {
+            return Some(Curve25519::identity());
+        }
+
+        let sign = (bytes[31] >> 7) & 1 == 1;
+        let mut xb = bytes.to_vec();
+        xb[31] &= 0x7f;
+        let x = Fp25519::from_bytes(&xb)?;
+
+        // Recover y from the curve equation y² = x³ + a·x, then select the root
+        // whose parity matches the stored sign bit.
+        let a = Fp25519::new(CURVE_A);
+        let rhs = x.pow(3) + a * x.clone();
+        let y = rhs.sqrt()?;
+        let y = if (y.value[0] & 1 == 1) == sign { y } else { -y };
+
+        let point = AffinePoint { x, y, infinity: false };
+        if !Curve25519::is_on_curve(&point) {
+            return None;
+        }
+        Some(point)
+    }
+}
+
 // Implement SquareRootField for Fp25519
 impl SquareRootField for Fp25519 {
     fn sqrt(&self) -> Option<Self> {
-        // Tonelli-Shanks algorithm would go here
-        // For now, we'll return None as a placeholder
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+        // Reject quadratic non-residues up front.
+        if self.legendre() != 1 {
+            return None;
+        }
+
+        // Atkin's shortcut for p ≡ 5 (mod 8): the candidate root is
+        // r = a^((p+3)/8); it is either correct or off by the factor
+        // sqrt(-1), and a final squaring decides between the two.
+        let r = self.pow_limbs(&SQRT_EXP);
+        if r.square() == *self {
+            return Some(r);
+        }
+        let r = r * Self::new(SQRT_MINUS_ONE);
+        if r.square() == *self {
+            return Some(r);
+        }
         None
     }
 
     fn legendre(&self) -> i8 {
         if self.is_zero() {
             0
-        } else if self.pow((PRIME_MODULUS[0] - 1) / 2) == Self::one() {
+        } else if self.pow_limbs(&LEGENDRE_EXP) == Self::one() {
             1
         } else {
             -1
@@ -189,6 +540,7 @@ pub struct Curve25519;
 impl EllipticCurve for Curve25519 {
     type BaseField = Fp25519;
     type Point = AffinePoint<Fp25519>;
+    type ProjectivePoint = ProjectivePoint<Fp25519>;
 
     fn identity() -> Self::Point {
         AffinePoint { x: Fp25519::zero(), y: Fp25519::zero(), infinity: true }
@@ -238,20 +590,66 @@ impl EllipticCurve for Curve25519 {
     }
 
     fn scalar_mul(point: &Self::Point, scalar: &[u64]) -> Self::Point {
-        let mut result = Self::identity();
-        let mut temp = point.clone();
+        // Accumulate in Jacobian coordinates with a left-to-right double-and-add
+        // so that the whole multiplication costs a single inversion, performed
+        // when we normalize back to affine at the end.
+        let mut acc = ProjectivePoint::identity();
+        for &limb in scalar.iter().rev() {
+            for i in (0..64).rev() {
+                acc = Self::double_projective(&acc);
+                if (limb >> i) & 1 == 1 {
+                    acc = Self::add_mixed(&acc, point);
+                }
+            }
+        }
+        acc.to_affine()
+    }
 
-        for &s in scalar {
-            let mut bits = s;
-            for _ in 0..64 {
-                if bits & 1 == 1 {
-                    result = Self::add_points(&result, &temp);
+    fn msm(bases: &[Self::Point], scalars: &[&[u64]]) -> Self::Point {
+        // Pippenger bucket method, far cheaper than one `scalar_mul` per base.
+        let n = bases.len().min(scalars.len());
+        if n == 0 {
+            return Self::identity();
+        }
+
+        // Window size ≈ ln(n) bits, floored at 3 for tiny inputs.
+        let c = ((n as f64).ln().ceil() as usize).max(3);
+        let max_bits = scalars[..n].iter().map(|s| scalar_bit_len(s)).max().unwrap_or(0);
+        if max_bits == 0 {
+            return Self::identity();
+        }
+        let num_windows = max_bits.div_ceil(c);
+        let num_buckets = (1usize << c) - 1;
+
+        // Process windows from most significant down, doubling the accumulator
+        // `c` times between each window.
+        let mut acc = ProjectivePoint::identity();
+        for window in (0..num_windows).rev() {
+            for _ in 0..c {
+                acc = Self::double_projective(&acc);
+            }
+
+            let mut buckets = vec![Bucket::Empty; num_buckets];
+            for i in 0..n {
+                let idx = read_window(scalars[i], window * c, c);
+                if idx != 0 {
+                    buckets[idx - 1].add_affine(&bases[i]);
                 }
-                temp = Self::double_point(&temp);
-                bits >>= 1;
             }
+
+            // Collapse buckets with a running-sum sweep from the top bucket so
+            // each bucket is scaled by its index without explicit multiplication.
+            let mut running = ProjectivePoint::identity();
+            let mut window_sum = ProjectivePoint::identity();
+            for bucket in buckets.iter().rev() {
+                running = Self::add_projective(&running, &bucket.to_projective());
+                window_sum = Self::add_projective(&window_sum, &running);
+            }
+
+            acc = Self::add_projective(&acc, &window_sum);
         }
-        result
+
+        acc.to_affine()
     }
 
     fn is_on_curve(point: &Self::Point) -> bool {
@@ -267,6 +665,96 @@ impl EllipticCurve for Curve25519 {
 }
 
 impl Curve25519 {
+    /// Constant-time X-only scalar multiplication via the Montgomery ladder on
+    /// the native Montgomery model `B·y² = x³ + A·x² + x`. The working point and
+    /// its neighbour are carried as projective `(X:Z)` pairs; each scalar bit
+    /// drives a conditional swap followed by the combined differential
+    /// add-and-double step with the curve constant `a24 = (A − 2)/4 = 121665`.
+    /// A single final inversion recovers the affine `x = X/Z`.
+    pub fn montgomery_ladder(x: Fp25519, scalar: &[u64]) -> Fp25519 {
+        let x1 = x.clone();
+        let a24 = Fp25519::new([121665, 0, 0, 0]);
+
+        let mut x2 = Fp25519::one();
+        let mut z2 = Fp25519::zero();
+        let mut x3 = x;
+        let mut z3 = Fp25519::one();
+        let mut swap: u64 = 0;
+
+        for &limb in scalar.iter().rev() {
+            for i in (0..64).rev() {
+                let bit = (limb >> i) & 1;
+                swap ^= bit;
+                Self::cswap(swap, &mut x2, &mut x3);
+                Self::cswap(swap, &mut z2, &mut z3);
+                swap = bit;
+
+                let a = x2.clone() + z2.clone();
+                let aa = a.square();
+                let b = x2.clone() - z2.clone();
+                let bb = b.square();
+                let e = aa.clone() - bb.clone();
+                let c = x3.clone() + z3.clone();
+                let d = x3.clone() - z3.clone();
+                let da = d * a;
+                let cb = c * b;
+                x3 = (da.clone() + cb.clone()).square();
+                z3 = x1.clone() * (da - cb).square();
+                x2 = aa.clone() * bb.clone();
+                z2 = e.clone() * (aa + a24.clone() * e);
+            }
+        }
+
+        Self::cswap(swap, &mut x2, &mut x3);
+        Self::cswap(swap, &mut z2, &mut z3);
+
+        match z2.inverse() {
+            Some(z_inv) => x2 * z_inv,
+            None => Fp25519::zero(),
+        }
+    }
+
+    // Constant-time conditional swap of two field elements over their limbs.
+    fn cswap(swap: u64, a: &mut Fp25519, b: &mut Fp25519) {
+        let mask = 0u64.wrapping_sub(swap);
+        for i in 0..4 {
+            let t = mask & (a.value[i] ^ b.value[i]);
+            a.value[i] ^= t;
+            b.value[i] ^= t;
+        }
+    }
+
+    /// Uncompressed 64-byte encoding: the x-coordinate followed by the full
+    /// y-coordinate, each as a canonical 32-byte field element. The point at
+    /// infinity uses the reserved all-ones encoding, whose coordinates are
+    /// non-canonical and therefore never collide with a finite point.
+    pub fn to_bytes_uncompressed(point: &AffinePoint<Fp25519>) -> Vec<u8> {
+        if point.infinity {
+            return vec![0xFF; 64];
+        }
+        let mut out = point.x.to_bytes();
+        out.extend_from_slice(&point.y.to_bytes());
+        out
+    }
+
+    /// Parses the 64-byte uncompressed encoding, rejecting non-canonical field
+    /// elements and points that do not lie on the curve.
+    pub fn from_bytes_uncompressed(bytes: &[u8]) -> Option<AffinePoint<Fp25519>> {
+        if bytes.len() != 64 {
+            return None;
+        }
+        if bytes == [0xFF; 64] {
+            return Some(Self::identity());
+        }
+        let x = Fp25519::from_bytes(&bytes[0..32])?;
+        let y = Fp25519::from_bytes(&bytes[32..64])?;
+        let point = AffinePoint { x, y, infinity: false };
+        if !Self::is_on_curve(&point) {
+            return None;
+        }
+        Some(point)
+    }
+
     fn double_point(p: &AffinePoint<Fp25519>) -> AffinePoint<Fp25519> {
         if p.infinity || p.y.is_zero() {
             return Self::identity();
@@ -282,4 +770,398 @@ impl Curve25519 {
 
         AffinePoint { x: x3, y: y3, infinity: false }
     }
+
+    /// Jacobian point doubling for `y² = x³ + ax + b`:
+    /// `S = 4·X·Y²`, `M = 3·X² + a·Z⁴`, `X' = M² − 2S`,
+    /// `Y' = M·(S − X') − 8·Y⁴`, `Z' = 2·Y·Z`.
+    fn double_projective(p: &ProjectivePoint<Fp25519>) -> ProjectivePoint<Fp25519> {
+        if p.is_identity() || p.y.is_zero() {
+            return ProjectivePoint::identity();
+        }
+
+        let a = Fp25519::new(CURVE_A);
+        let two = Fp25519::new([2, 0, 0, 0]);
+        let three = Fp25519::new([3, 0, 0, 0]);
+        let four = Fp25519::new([4, 0, 0, 0]);
+        let eight = Fp25519::new([8, 0, 0, 0]);
+
+        let yy = p.y.square();
+        let s = four * p.x.clone() * yy.clone();
+        let z2 = p.z.square();
+        let m = three * p.x.square() + a * z2.square();
+        let x3 = m.square() - two.clone() * s.clone();
+        let y3 = m * (s - x3.clone()) - eight * yy.square();
+        let z3 = two * p.y.clone() * p.z.clone();
+
+        ProjectivePoint { x: x3, y: y3, z: z3 }
+    }
+
+    /// Mixed Jacobian/affine addition (`Z₂ = 1`):
+    /// `U2 = X₂·Z₁²`, `S2 = Y₂·Z₁³`, `H = U2 − X₁`, `r = S2 − Y₁`,
+    /// `X₃ = r² − H³ − 2·X₁·H²`, `Y₃ = r·(X₁·H² − X₃) − Y₁·H³`, `Z₃ = Z₁·H`.
+    fn add_mixed(
+        p: &ProjectivePoint<Fp25519>,
+        q: &AffinePoint<Fp25519>,
+    ) -> ProjectivePoint<Fp25519> {
+        if p.is_identity() {
+            return ProjectivePoint::from_affine(q);
+        }
+        if q.infinity {
+            return p.clone();
+        }
+
+        let two = Fp25519::new([2, 0, 0, 0]);
+        let z1_2 = p.z.square();
+        let z1_3 = z1_2.clone() * p.z.clone();
+        let u2 = q.x.clone() * z1_2;
+        let s2 = q.y.clone() * z1_3;
+        let h = u2 - p.x.clone();
+        let r = s2 - p.y.clone();
+
+        if h.is_zero() {
+            if r.is_zero() {
+                return Self::double_projective(p);
+            }
+            return ProjectivePoint::identity();
+        }
+
+        let h2 = h.square();
+        let h3 = h2.clone() * h.clone();
+        let x1h2 = p.x.clone() * h2;
+        let x3 = r.square() - h3.clone() - two * x1h2.clone();
+        let y3 = r.clone() * (x1h2 - x3.clone()) - p.y.clone() * h3;
+        let z3 = p.z.clone() * h;
+
+        ProjectivePoint { x: x3, y: y3, z: z3 }
+    }
+
+    /// General Jacobian point addition for two projective points.
+    /// `U1 = X₁·Z₂²`, `U2 = X₂·Z₁²`, `S1 = Y₁·Z₂³`, `S2 = Y₂·Z₁³`,
+    /// `H = U2 − U1`, `r = S2 − S1`, `X₃ = r² − H³ − 2·U1·H²`,
+    /// `Y₃ = r·(U1·H² − X₃) − S1·H³`, `Z₃ = Z₁·Z₂·H`.
+    fn add_projective(
+        p1: &ProjectivePoint<Fp25519>,
+        p2: &ProjectivePoint<Fp25519>,
+    ) -> ProjectivePoint<Fp25519> {
+        if p1.is_identity() {
+            return p2.clone();
+        }
+        if p2.is_identity() {
+            return p1.clone();
+        }
+
+        let two = Fp25519::new([2, 0, 0, 0]);
+        let z1_2 = p1.z.square();
+        let z2_2 = p2.z.square();
+        let u1 = p1.x.clone() * z2_2.clone();
+        let u2 = p2.x.clone() * z1_2.clone();
+        let s1 = p1.y.clone() * z2_2 * p2.z.clone();
+        let s2 = p2.y.clone() * z1_2 * p1.z.clone();
+        let h = u2 - u1.clone();
+        let r = s2 - s1.clone();
+
+        if h.is_zero() {
+            if r.is_zero() {
+                return Self::double_projective(p1);
+            }
+            return ProjectivePoint::identity();
+        }
+
+        let h2 = h.square();
+        let h3 = h2.clone() * h.clone();
+        let u1h2 = u1 * h2;
+        let x3 = r.square() - h3.clone() - two * u1h2.clone();
+        let y3 = r.clone() * (u1h2 - x3.clone()) - s1 * h3;
+        let z3 = p1.z.clone() * p2.z.clone() * h;
+
+        ProjectivePoint { x: x3, y: y3, z: z3 }
+    }
+}
+
+/// A Pippenger bucket, carrying a cheap empty/affine/projective state so that
+/// untouched buckets cost nothing and the first base added avoids a projective
+/// mixed-add against the identity.
+#[derive(Clone)]
+enum Bucket {
+    Empty,
+    Affine(AffinePoint<Fp25519>),
+    Projective(ProjectivePoint<Fp25519>),
+}
+
+impl Bucket {
+    fn add_affine(&mut self, p: &AffinePoint<Fp25519>) {
+        *self = match self {
+            Bucket::Empty => Bucket::Affine(p.clone()),
+            Bucket::Affine(q) => {
+                Bucket::Projective(Curve25519::add_mixed(&ProjectivePoint::from_affine(q), p))
+            },
+            Bucket::Projective(acc) => Bucket::Projective(Curve25519::add_mixed(acc, p)),
+        };
+    }
+
+    fn to_projective(&self) -> ProjectivePoint<Fp25519> {
+        match self {
+            Bucket::Empty => ProjectivePoint::identity(),
+            Bucket::Affine(q) => ProjectivePoint::from_affine(q),
+            Bucket::Projective(acc) => acc.clone(),
+        }
+    }
+}
+
+/// Reads the `c`-bit window of `scalar` starting at bit `start`.
+fn read_window(scalar: &[u64], start: usize, c: usize) -> usize {
+    let mut val = 0usize;
+    for j in 0..c {
+        let bit = start + j;
+        let limb = bit / 64;
+        let off = bit % 64;
+        if limb < scalar.len() && (scalar[limb] >> off) & 1 == 1 {
+            val |= 1 << j;
+        }
+    }
+    val
+}
+
+/// Returns the index of the highest set bit plus one (the bit length).
+fn scalar_bit_len(s: &[u64]) -> usize {
+    for i in (0..s.len()).rev() {
+        if s[i] != 0 {
+            return i * 64 + (64 - s[i].leading_zeros() as usize);
+        }
+    }
+    0
+}
+
+
+#[cfg(test)]
+mod field_tests {
+    use super::*;
+
+    // Smallest valid affine point on y² = x³ + a·x, used as a test base point.
+    fn sample_point() -> AffinePoint<Fp25519> {
+        let a = Fp25519::new(CURVE_A);
+        let mut xv = 1u64;
+        loop {
+            let x = Fp25519::new([xv, 0, 0, 0]);
+            let rhs = x.pow(3) + a.clone() * x.clone();
+            if let Some(y) = rhs.sqrt() {
+                let p = AffinePoint { x, y, infinity: false };
+                if Curve25519::is_on_curve(&p) {
+                    return p;
+                }
+            }
+            xv += 1;
+        }
+    }
+
+    fn fp(n: u64) -> Fp25519 { Fp25519::new([n, 0, 0, 0]) }
+
+    #[test]
+    fn field_mul_reduces_modulo_p() {
+        // (p − 1)² ≡ 1 (mod p); the broken multiply that dropped the high half
+        // could never satisfy this.
+        let p_minus_1 = -Fp25519::one();
+        assert_eq!(p_minus_1.clone() * p_minus_1, Fp25519::one());
+        // Distributivity across a multi-limb boundary.
+        let a = fp(0xFFFF_FFFF_FFFF_FFFF);
+        let b = fp(0x1234_5678);
+        assert_eq!(a.clone() * (b.clone() + fp(1)), a.clone() * b + a);
+    }
+
+    #[test]
+    fn field_inverse_round_trips() {
+        for n in 1..50u64 {
+            let a = fp(n);
+            assert_eq!(a.clone() * a.inverse().unwrap(), Fp25519::one());
+        }
+    }
+
+    #[test]
+    fn scalar_mul_matches_repeated_addition() {
+        let p = sample_point();
+        let two = Curve25519::scalar_mul(&p, &[2]);
+        let three = Curve25519::scalar_mul(&p, &[3]);
+        let five = Curve25519::scalar_mul(&p, &[5]);
+        assert_eq!(two, Curve25519::add_points(&p, &p));
+        assert_eq!(three, Curve25519::add_points(&two, &p));
+        assert_eq!(five, Curve25519::add_points(&two, &three));
+        assert!(Curve25519::is_on_curve(&five));
+    }
+
+    #[test]
+    fn projective_round_trip() {
+        let p = sample_point();
+        let proj = ProjectivePoint::from_affine(&p);
+        assert_eq!(proj.to_affine(), p);
+    }
+
+    #[test]
+    fn msm_matches_naive_combination() {
+        let p = sample_point();
+        let q = Curve25519::scalar_mul(&p, &[7]);
+        let bases = [p.clone(), q.clone()];
+        let s0: &[u64] = &[3];
+        let s1: &[u64] = &[5];
+        let got = Curve25519::msm(&bases, &[s0, s1]);
+        let want = Curve25519::add_points(
+            &Curve25519::scalar_mul(&p, &[3]),
+            &Curve25519::scalar_mul(&q, &[5]),
+        );
+        assert_eq!(got, want);
+    }
+}
+
+#[cfg(test)]
+mod sqrt_tests {
+    use super::*;
+
+    fn fp(n: u64) -> Fp25519 { Fp25519::new([n, 0, 0, 0]) }
+
+    #[test]
+    fn legendre_classifies_residues() {
+        assert_eq!(Fp25519::zero().legendre(), 0);
+        // A known square has symbol +1.
+        let sq = fp(4);
+        assert_eq!(sq.legendre(), 1);
+        // 2 is a non-residue modulo 2^255 - 19.
+        assert_eq!(fp(2).legendre(), -1);
+    }
+
+    #[test]
+    fn sqrt_round_trips_for_squares() {
+        // Before the fix the single-limb Legendre exponent rejected every
+        // genuine square, so sqrt returned None here.
+        for n in 1..40u64 {
+            let a = fp(n);
+            let sq = a.square();
+            let root = sq.sqrt().expect("square must have a root");
+            assert_eq!(root.square(), sq);
+        }
+    }
+
+    #[test]
+    fn sqrt_rejects_non_residues() {
+        assert!(fp(2).sqrt().is_none());
+    }
+}
+
+#[cfg(test)]
+mod serde_tests {
+    use super::*;
+
+    fn fp(n: u64) -> Fp25519 { Fp25519::new([n, 0, 0, 0]) }
+
+    // Smallest valid affine point on y² = x³ + a·x.
+    fn sample_point() -> AffinePoint<Fp25519> {
+        let a = Fp25519::new(CURVE_A);
+        let mut xv = 1u64;
+        loop {
+            let x = fp(xv);
+            let rhs = x.pow(3) + a.clone() * x.clone();
+            if let Some(y) = rhs.sqrt() {
+                let p = AffinePoint { x, y, infinity: false };
+                if Curve25519::is_on_curve(&p) {
+                    return p;
+                }
+            }
+            xv += 1;
+        }
+    }
+
+    #[test]
+    fn field_serialization_round_trips_and_rejects_non_canonical() {
+        for n in [0u64, 1, 2, 486662, u64::MAX] {
+            let a = fp(n);
+            assert_eq!(Fp25519::from_bytes(&a.to_bytes()), Some(a));
+        }
+        // p itself is non-canonical and must be rejected.
+        let mut p_bytes = Vec::new();
+        for limb in PRIME_MODULUS {
+            p_bytes.extend_from_slice(&limb.to_le_bytes());
+        }
+        assert!(Fp25519::from_bytes(&p_bytes).is_none());
+    }
+
+    #[test]
+    fn point_compression_round_trips() {
+        let p = sample_point();
+        let round = AffinePoint::<Fp25519>::from_bytes(&p.to_bytes()).unwrap();
+        assert_eq!(round, p);
+
+        let inf = Curve25519::identity();
+        assert_eq!(AffinePoint::<Fp25519>::from_bytes(&inf.to_bytes()), Some(inf));
+
+        // The 2-torsion point (0, 0) must not alias the point at infinity.
+        let two_torsion = AffinePoint { x: Fp25519::zero(), y: Fp25519::zero(), infinity: false };
+        let decoded = AffinePoint::<Fp25519>::from_bytes(&two_torsion.to_bytes()).unwrap();
+        assert!(!decoded.infinity);
+        assert_eq!(decoded, two_torsion);
+    }
+
+    #[test]
+    fn uncompressed_round_trips() {
+        let p = sample_point();
+        let bytes = Curve25519::to_bytes_uncompressed(&p);
+        assert_eq!(Curve25519::from_bytes_uncompressed(&bytes), Some(p));
+    }
+
+    #[test]
+    fn off_curve_compressed_is_rejected() {
+        // x with no corresponding y on the curve must fail to decompress.
+        let mut rejected = 0;
+        for xv in 1..60u64 {
+            let x = fp(xv);
+            let rhs = x.pow(3) + Fp25519::new(CURVE_A) * x.clone();
+            if rhs.sqrt().is_none() {
+                let bytes = x.to_bytes();
+                assert!(AffinePoint::<Fp25519>::from_bytes(&bytes).is_none());
+                rejected += 1;
+            }
+        }
+        assert!(rejected > 0, "expected at least one off-curve x");
+    }
+}
+
+#[cfg(test)]
+mod ladder_tests {
+    use super::*;
+
+    // Interprets 32 little-endian bytes as a field element's limbs.
+    fn limbs_from_le(bytes: &[u8; 32]) -> [u64; 4] {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let mut chunk = [0u8; 8];
+            chunk.copy_from_slice(&bytes[i * 8..i * 8 + 8]);
+            *limb = u64::from_le_bytes(chunk);
+        }
+        limbs
+    }
+
+    fn hex32(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).unwrap();
+        }
+        out
+    }
+
+    #[test]
+    fn x25519_rfc7748_test_vector() {
+        // RFC 7748 §5.2, first X25519 test vector.
+        let mut scalar = hex32("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4");
+        let mut u = hex32("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c");
+        let expected = hex32("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552");
+
+        // Scalar clamping and high-bit masking of the u-coordinate per RFC 7748.
+        scalar[0] &= 248;
+        scalar[31] &= 127;
+        scalar[31] |= 64;
+        u[31] &= 127;
+
+        let x = Fp25519::new(limbs_from_le(&u));
+        let scalar_limbs = limbs_from_le(&scalar);
+        let result = Curve25519::montgomery_ladder(x, &scalar_limbs);
+
+        assert_eq!(result.to_bytes(), expected.to_vec());
+    }
 }
@@ -1,5 +1,5 @@
 use core::{
-    fmt::{Debug, Display},
+    fmt::{Debug, Display, Formatter},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
@@ -63,3 +63,249 @@ pub trait SquareRootField: Field {
     /// Returns the Legendre symbol of this element
     fn legendre(&self) -> i8;
 }
+
+/// Serializes a value into its canonical byte encoding, mirroring the role of
+/// ark-serialize's `CanonicalSerialize`.
+pub trait ToBytes {
+    /// Returns the canonical byte encoding of `self`.
+    fn to_bytes(&self) -> Vec<u8>;
+}
+
+/// Deserializes a value from its canonical byte encoding, rejecting any
+/// non-canonical input (as `CanonicalDeserialize` does).
+pub trait FromBytes: Sized {
+    /// Parses a value from its canonical encoding, returning `None` if the
+    /// bytes are malformed or non-canonical.
+    fn from_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+/// Configuration for a quadratic extension `F[u]/(u² − β)`, supplying the base
+/// field and the non-residue `β`. Concrete towers (e.g. `Fp2`, `Fp12`) are
+/// built by implementing this for a marker type.
+pub trait QuadraticExtensionConfig: Clone + Debug + PartialEq {
+    /// The field being extended.
+    type BaseField: Field;
+
+    /// The non-residue `β` with `u² = β`.
+    fn non_residue() -> Self::BaseField;
+}
+
+/// Configuration for a cubic extension `F[v]/(v³ − β)`.
+pub trait CubicExtensionConfig: Clone + Debug + PartialEq {
+    /// The field being extended.
+    type BaseField: Field;
+
+    /// The non-residue `β` with `v³ = β`.
+    fn non_residue() -> Self::BaseField;
+}
+
+/// A generic quadratic extension field element `c0 + c1·u` where `u² = β`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuadraticExtension<P: QuadraticExtensionConfig> {
+    pub c0: P::BaseField,
+    pub c1: P::BaseField,
+}
+
+impl<P: QuadraticExtensionConfig> QuadraticExtension<P> {
+    /// Builds the element `c0 + c1·u`.
+    pub fn new(c0: P::BaseField, c1: P::BaseField) -> Self { Self { c0, c1 } }
+}
+
+impl<P: QuadraticExtensionConfig> Field for QuadraticExtension<P> {
+    fn characteristic() -> Vec<u64> { P::BaseField::characteristic() }
+
+    fn one() -> Self { Self { c0: P::BaseField::one(), c1: P::BaseField::zero() } }
+
+    fn zero() -> Self { Self { c0: P::BaseField::zero(), c1: P::BaseField::zero() } }
+
+    fn is_zero(&self) -> bool { self.c0.is_zero() && self.c1.is_zero() }
+
+    fn inverse(&self) -> Option<Self> {
+        // (c0 + c1·u)⁻¹ = (c0 − c1·u) / (c0² − β·c1²)
+        let norm = self.c0.square() - P::non_residue() * self.c1.square();
+        norm.inverse().map(|n| Self {
+            c0: self.c0.clone() * n.clone(),
+            c1: -(self.c1.clone() * n),
+        })
+    }
+
+    fn pow(&self, exp: u64) -> Self { pow_by_squaring(self.clone(), exp) }
+}
+
+impl<P: QuadraticExtensionConfig> Add for QuadraticExtension<P> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self { c0: self.c0 + other.c0, c1: self.c1 + other.c1 }
+    }
+}
+
+impl<P: QuadraticExtensionConfig> Sub for QuadraticExtension<P> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self { c0: self.c0 - other.c0, c1: self.c1 - other.c1 }
+    }
+}
+
+impl<P: QuadraticExtensionConfig> Mul for QuadraticExtension<P> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        // (a0 + a1·u)(b0 + b1·u) = (a0·b0 + β·a1·b1) + (a0·b1 + a1·b0)·u
+        let v0 = self.c0.clone() * other.c0.clone();
+        let v1 = self.c1.clone() * other.c1.clone();
+        let c0 = v0 + P::non_residue() * v1;
+        let c1 = self.c0 * other.c1 + self.c1 * other.c0;
+        Self { c0, c1 }
+    }
+}
+
+impl<P: QuadraticExtensionConfig> Neg for QuadraticExtension<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self { Self { c0: -self.c0, c1: -self.c1 } }
+}
+
+impl<P: QuadraticExtensionConfig> AddAssign for QuadraticExtension<P> {
+    fn add_assign(&mut self, other: Self) { *self = self.clone() + other; }
+}
+
+impl<P: QuadraticExtensionConfig> SubAssign for QuadraticExtension<P> {
+    fn sub_assign(&mut self, other: Self) { *self = self.clone() - other; }
+}
+
+impl<P: QuadraticExtensionConfig> MulAssign for QuadraticExtension<P> {
+    fn mul_assign(&mut self, other: Self) { *self = self.clone() * other; }
+}
+
+impl<P: QuadraticExtensionConfig> Display for QuadraticExtension<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "({} + {}*u)", self.c0, self.c1)
+    }
+}
+
+/// A generic cubic extension field element `c0 + c1·v + c2·v²` where `v³ = β`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CubicExtension<P: CubicExtensionConfig> {
+    pub c0: P::BaseField,
+    pub c1: P::BaseField,
+    pub c2: P::BaseField,
+}
+
+impl<P: CubicExtensionConfig> CubicExtension<P> {
+    /// Builds the element `c0 + c1·v + c2·v²`.
+    pub fn new(c0: P::BaseField, c1: P::BaseField, c2: P::BaseField) -> Self {
+        Self { c0, c1, c2 }
+    }
+}
+
+impl<P: CubicExtensionConfig> Field for CubicExtension<P> {
+    fn characteristic() -> Vec<u64> { P::BaseField::characteristic() }
+
+    fn one() -> Self {
+        Self { c0: P::BaseField::one(), c1: P::BaseField::zero(), c2: P::BaseField::zero() }
+    }
+
+    fn zero() -> Self {
+        Self { c0: P::BaseField::zero(), c1: P::BaseField::zero(), c2: P::BaseField::zero() }
+    }
+
+    fn is_zero(&self) -> bool { self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero() }
+
+    fn inverse(&self) -> Option<Self> {
+        // Standard cofactor inverse for a cubic extension with `v³ = β`.
+        let beta = P::non_residue();
+        let t0 = self.c0.square() - beta.clone() * self.c1.clone() * self.c2.clone();
+        let t1 = beta.clone() * self.c2.square() - self.c0.clone() * self.c1.clone();
+        let t2 = self.c1.square() - self.c0.clone() * self.c2.clone();
+        let norm = self.c0.clone() * t0.clone()
+            + beta.clone() * self.c2.clone() * t1.clone()
+            + beta * self.c1.clone() * t2.clone();
+        norm.inverse().map(|n| Self {
+            c0: t0 * n.clone(),
+            c1: t1 * n.clone(),
+            c2: t2 * n,
+        })
+    }
+
+    fn pow(&self, exp: u64) -> Self { pow_by_squaring(self.clone(), exp) }
+}
+
+impl<P: CubicExtensionConfig> Add for CubicExtension<P> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self { c0: self.c0 + other.c0, c1: self.c1 + other.c1, c2: self.c2 + other.c2 }
+    }
+}
+
+impl<P: CubicExtensionConfig> Sub for CubicExtension<P> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self { c0: self.c0 - other.c0, c1: self.c1 - other.c1, c2: self.c2 - other.c2 }
+    }
+}
+
+impl<P: CubicExtensionConfig> Mul for CubicExtension<P> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        // Schoolbook product reduced with `v³ = β`.
+        let beta = P::non_residue();
+        let a0 = self.c0.clone();
+        let a1 = self.c1.clone();
+        let a2 = self.c2.clone();
+        let b0 = other.c0.clone();
+        let b1 = other.c1.clone();
+        let b2 = other.c2.clone();
+
+        let c0 = a0.clone() * b0.clone()
+            + beta.clone() * (a1.clone() * b2.clone() + a2.clone() * b1.clone());
+        let c1 = a0.clone() * b1.clone()
+            + a1.clone() * b0.clone()
+            + beta * a2.clone() * b2.clone();
+        let c2 = a0 * b2 + a1 * b1 + a2 * b0;
+        Self { c0, c1, c2 }
+    }
+}
+
+impl<P: CubicExtensionConfig> Neg for CubicExtension<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self { Self { c0: -self.c0, c1: -self.c1, c2: -self.c2 } }
+}
+
+impl<P: CubicExtensionConfig> AddAssign for CubicExtension<P> {
+    fn add_assign(&mut self, other: Self) { *self = self.clone() + other; }
+}
+
+impl<P: CubicExtensionConfig> SubAssign for CubicExtension<P> {
+    fn sub_assign(&mut self, other: Self) { *self = self.clone() - other; }
+}
+
+impl<P: CubicExtensionConfig> MulAssign for CubicExtension<P> {
+    fn mul_assign(&mut self, other: Self) { *self = self.clone() * other; }
+}
+
+impl<P: CubicExtensionConfig> Display for CubicExtension<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "({} + {}*v + {}*v^2)", self.c0, self.c1, self.c2)
+    }
+}
+
+/// Shared square-and-multiply exponentiation for the extension towers.
+fn pow_by_squaring<F: Field>(base: F, exp: u64) -> F {
+    let mut base = base;
+    let mut result = F::one();
+    let mut e = exp;
+    while e > 0 {
+        if e & 1 == 1 {
+            result = result * base.clone();
+        }
+        base = base.square();
+        e >>= 1;
+    }
+    result
+}
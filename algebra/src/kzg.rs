@@ -0,0 +1,183 @@
+use crate::{
+    ec::{EllipticCurve, Engine},
+    field::{Field, PrimeField},
+};
+
+// Type aliases for the engine's source-group point types.
+type G1Point<E> = <<E as Engine>::G1 as EllipticCurve>::Point;
+type G2Point<E> = <<E as Engine>::G2 as EllipticCurve>::Point;
+
+/// A dense univariate polynomial over a field, coefficients stored
+/// low-order-first (`coeffs[i]` multiplies `xⁱ`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polynomial<F: Field> {
+    pub coeffs: Vec<F>,
+}
+
+impl<F: Field> Polynomial<F> {
+    /// Builds a polynomial from its low-order-first coefficients.
+    pub fn new(coeffs: Vec<F>) -> Self { Self { coeffs } }
+
+    /// Evaluates the polynomial at `x` by Horner's rule.
+    pub fn evaluate(&self, x: &F) -> F {
+        let mut acc = F::zero();
+        for c in self.coeffs.iter().rev() {
+            acc = acc * x.clone() + c.clone();
+        }
+        acc
+    }
+
+    /// Divides by the linear factor `(X − z)`, returning the quotient and the
+    /// remainder. For the KZG quotient the remainder is zero by construction.
+    pub fn divide_by_linear(&self, z: &F) -> (Polynomial<F>, F) {
+        let n = self.coeffs.len();
+        if n == 0 {
+            return (Polynomial::new(Vec::new()), F::zero());
+        }
+        let mut quotient = vec![F::zero(); n - 1];
+        let mut remainder = self.coeffs[n - 1].clone();
+        for i in (0..n - 1).rev() {
+            quotient[i] = remainder.clone();
+            remainder = self.coeffs[i].clone() + z.clone() * remainder;
+        }
+        (Polynomial::new(quotient), remainder)
+    }
+}
+
+/// A KZG polynomial-commitment scheme over a pairing engine `E`. The structured
+/// reference string holds `{g, g·τ, g·τ², …}` in `G1` and `{h, h·τ}` in `G2`.
+pub struct Kzg<E: Engine> {
+    srs_g1: Vec<G1Point<E>>,
+    h:      G2Point<E>,
+    h_tau:  G2Point<E>,
+}
+
+impl<E: Engine> Kzg<E>
+where E::Fr: PrimeField
+{
+    /// Generates the structured reference string for polynomials up to
+    /// `max_degree` from a secret evaluation point `tau`.
+    pub fn setup(tau: &E::Fr, max_degree: usize) -> Self {
+        let g = <E::G1 as EllipticCurve>::generator();
+        let h = <E::G2 as EllipticCurve>::generator();
+
+        let mut srs_g1 = Vec::with_capacity(max_degree + 1);
+        for i in 0..=max_degree {
+            let power = scalar_limbs(&tau.pow(i as u64));
+            srs_g1.push(<E::G1 as EllipticCurve>::scalar_mul(&g, &power));
+        }
+        let h_tau = <E::G2 as EllipticCurve>::scalar_mul(&h, &scalar_limbs(tau));
+
+        Self { srs_g1, h, h_tau }
+    }
+
+    /// Commits to a polynomial as the MSM of its coefficients against the SRS
+    /// powers of `τ` in `G1`.
+    pub fn commit(&self, poly: &Polynomial<E::Fr>) -> G1Point<E> {
+        let n = poly.coeffs.len();
+        let scalars: Vec<Vec<u64>> = poly.coeffs.iter().map(scalar_limbs).collect();
+        let refs: Vec<&[u64]> = scalars.iter().map(Vec::as_slice).collect();
+        <E::G1 as EllipticCurve>::msm(&self.srs_g1[..n], &refs)
+    }
+
+    /// Opens `poly` at `z`, returning the claimed value `p(z)` together with the
+    /// proof: a commitment to the quotient `(p(x) − p(z)) / (x − z)`.
+    pub fn open(&self, poly: &Polynomial<E::Fr>, z: &E::Fr) -> (E::Fr, G1Point<E>) {
+        let value = poly.evaluate(z);
+
+        let mut shifted = poly.clone();
+        if shifted.coeffs.is_empty() {
+            shifted.coeffs.push(-value.clone());
+        } else {
+            shifted.coeffs[0] = shifted.coeffs[0].clone() - value.clone();
+        }
+        let (quotient, _remainder) = shifted.divide_by_linear(z);
+        let proof = self.commit(&quotient);
+
+        (value, proof)
+    }
+
+    /// Verifies an opening by checking the pairing equation
+    /// `e(commitment − value·g, h) == e(proof, h·τ − z·h)`. The point
+    /// subtractions are expressed through field negation of the scalars so only
+    /// scalar multiplications and group additions are required.
+    pub fn verify(
+        &self,
+        commitment: &G1Point<E>,
+        z: &E::Fr,
+        value: &E::Fr,
+        proof: &G1Point<E>,
+    ) -> bool {
+        let g = self.srs_g1[0].clone();
+        let neg_value_g =
+            <E::G1 as EllipticCurve>::scalar_mul(&g, &scalar_limbs(&-value.clone()));
+        let lhs_g1 = <E::G1 as EllipticCurve>::add_points(commitment, &neg_value_g);
+
+        let neg_z_h =
+            <E::G2 as EllipticCurve>::scalar_mul(&self.h, &scalar_limbs(&-z.clone()));
+        let rhs_g2 = <E::G2 as EllipticCurve>::add_points(&self.h_tau, &neg_z_h);
+
+        E::pairing(&lhs_g1, &self.h) == E::pairing(proof, &rhs_g2)
+    }
+}
+
+/// Decomposes a scalar-field element into little-endian `u64` limbs for the
+/// `EllipticCurve` scalar-multiplication and MSM APIs.
+fn scalar_limbs<F: PrimeField>(f: &F) -> Vec<u64> {
+    let bits = f.to_bits();
+    let mut limbs = vec![0u64; bits.len().div_ceil(64).max(1)];
+    for (i, bit) in bits.iter().enumerate() {
+        if *bit {
+            limbs[i / 64] |= 1 << (i % 64);
+        }
+    }
+    limbs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pairing::{ExampleEngine, Fr};
+
+    fn fr(n: u64) -> Fr { <Fr as PrimeField>::from_u64(n) }
+
+    // p(x) = 3 + x + 2x² over the scalar field.
+    fn sample_poly() -> Polynomial<Fr> {
+        Polynomial::new(vec![fr(3), fr(1), fr(2)])
+    }
+
+    #[test]
+    fn valid_opening_verifies() {
+        let kzg = Kzg::<ExampleEngine>::setup(&fr(5), 2);
+        let poly = sample_poly();
+        let commitment = kzg.commit(&poly);
+
+        let z = fr(4);
+        let (value, proof) = kzg.open(&poly, &z);
+        assert_eq!(value, poly.evaluate(&z));
+        assert!(kzg.verify(&commitment, &z, &value, &proof));
+    }
+
+    #[test]
+    fn forged_value_is_rejected() {
+        let kzg = Kzg::<ExampleEngine>::setup(&fr(5), 2);
+        let poly = sample_poly();
+        let commitment = kzg.commit(&poly);
+
+        let z = fr(4);
+        let (value, proof) = kzg.open(&poly, &z);
+        // Claiming a different evaluation must fail the pairing check.
+        assert!(!kzg.verify(&commitment, &z, &(value + fr(1)), &proof));
+    }
+
+    #[test]
+    fn proof_does_not_transfer_to_other_point() {
+        let kzg = Kzg::<ExampleEngine>::setup(&fr(5), 2);
+        let poly = sample_poly();
+        let commitment = kzg.commit(&poly);
+
+        let (value, proof) = kzg.open(&poly, &fr(4));
+        // A proof for z = 4 must not verify the same value at z = 6.
+        assert!(!kzg.verify(&commitment, &fr(6), &value, &proof));
+    }
+}
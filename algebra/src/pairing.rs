@@ -0,0 +1,502 @@
+use core::{
+    fmt::{Debug, Display, Formatter},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    ec::{AffinePoint, EllipticCurve, Engine, PairingCurve, ProjectivePoint},
+    field::{
+        Field, PrimeField, QuadraticExtension, QuadraticExtensionConfig, SquareRootField,
+    },
+};
+
+// A small supersingular example used to exercise the pairing machinery with
+// exact, checkable arithmetic. The base field is GF(p) with p ≡ 3 (mod 4), so
+// the curve E: y² = x³ + x has trace zero and `#E(Fp) = p + 1`. The prime
+// subgroup of order `GROUP_ORDER` supports an embedding degree of two, placing the
+// pairing values in the quadratic extension `Fp2 = Fp[u]/(u² + 1)`.
+const FIELD_MODULUS: u64 = 103;
+const GROUP_ORDER: u64 = 13;
+const GENERATOR_X: u64 = 18;
+const GENERATOR_Y: u64 = 44;
+// (p² − 1) / r, the final-exponentiation power mapping Miller values into the
+// group of r-th roots of unity.
+const FINAL_EXPONENT: u64 = (FIELD_MODULUS * FIELD_MODULUS - 1) / GROUP_ORDER;
+
+/// The base field GF(103) of the example curve.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fq {
+    value: u64,
+}
+
+impl Fq {
+    /// Reduces `value` modulo the field characteristic.
+    pub fn new(value: u64) -> Self { Self { value: value % FIELD_MODULUS } }
+}
+
+impl Field for Fq {
+    fn characteristic() -> Vec<u64> { vec![FIELD_MODULUS] }
+
+    fn one() -> Self { Self { value: 1 } }
+
+    fn zero() -> Self { Self { value: 0 } }
+
+    fn is_zero(&self) -> bool { self.value == 0 }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Fermat inverse a^(p−2) over a small prime fits in the `pow` API.
+            Some(self.pow(FIELD_MODULUS - 2))
+        }
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        let mut base = *self;
+        let mut result = Self::one();
+        let mut e = exp;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result * base;
+            }
+            base = base.square();
+            e >>= 1;
+        }
+        result
+    }
+}
+
+impl SquareRootField for Fq {
+    fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+        if self.legendre() != 1 {
+            return None;
+        }
+        // p ≡ 3 (mod 4): the root is a^((p+1)/4).
+        let r = self.pow((FIELD_MODULUS + 1) / 4);
+        if r.square() == *self { Some(r) } else { None }
+    }
+
+    fn legendre(&self) -> i8 {
+        if self.is_zero() {
+            0
+        } else if self.pow((FIELD_MODULUS - 1) / 2) == Self::one() {
+            1
+        } else {
+            -1
+        }
+    }
+}
+
+impl Add for Fq {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self { Self::new(self.value + other.value) }
+}
+
+impl Sub for Fq {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.value + FIELD_MODULUS - other.value)
+    }
+}
+
+impl Mul for Fq {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self { Self::new(self.value * other.value) }
+}
+
+impl Neg for Fq {
+    type Output = Self;
+
+    fn neg(self) -> Self { Self::new(FIELD_MODULUS - self.value % FIELD_MODULUS) }
+}
+
+impl AddAssign for Fq {
+    fn add_assign(&mut self, other: Self) { *self = *self + other; }
+}
+
+impl SubAssign for Fq {
+    fn sub_assign(&mut self, other: Self) { *self = *self - other; }
+}
+
+impl MulAssign for Fq {
+    fn mul_assign(&mut self, other: Self) { *self = *self * other; }
+}
+
+impl Display for Fq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result { write!(f, "{}", self.value) }
+}
+
+/// The scalar field GF(13) indexing the prime-order subgroup. KZG and the
+/// bilinearity checks operate over this field.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fr {
+    value: u64,
+}
+
+impl Fr {
+    /// Reduces `value` modulo the group order.
+    pub fn new(value: u64) -> Self { Self { value: value % GROUP_ORDER } }
+}
+
+impl Field for Fr {
+    fn characteristic() -> Vec<u64> { vec![GROUP_ORDER] }
+
+    fn one() -> Self { Self { value: 1 } }
+
+    fn zero() -> Self { Self { value: 0 } }
+
+    fn is_zero(&self) -> bool { self.value == 0 }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() { None } else { Some(self.pow(GROUP_ORDER - 2)) }
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        let mut base = *self;
+        let mut result = Self::one();
+        let mut e = exp;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result * base;
+            }
+            base = base.square();
+            e >>= 1;
+        }
+        result
+    }
+}
+
+impl PrimeField for Fr {
+    fn modulus() -> Vec<u64> { vec![GROUP_ORDER] }
+
+    fn from_u64(n: u64) -> Self { Self::new(n) }
+
+    fn to_bits(&self) -> Vec<bool> {
+        let bits = 64 - (GROUP_ORDER - 1).leading_zeros() as usize;
+        (0..bits).map(|i| (self.value >> i) & 1 == 1).collect()
+    }
+
+    fn from_bits(bits: &[bool]) -> Option<Self> {
+        let mut value = 0u64;
+        for (i, bit) in bits.iter().enumerate() {
+            if *bit {
+                value |= 1 << i;
+            }
+        }
+        Some(Self::new(value))
+    }
+}
+
+impl Add for Fr {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self { Self::new(self.value + other.value) }
+}
+
+impl Sub for Fr {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.value + GROUP_ORDER - other.value)
+    }
+}
+
+impl Mul for Fr {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self { Self::new(self.value * other.value) }
+}
+
+impl Neg for Fr {
+    type Output = Self;
+
+    fn neg(self) -> Self { Self::new(GROUP_ORDER - self.value % GROUP_ORDER) }
+}
+
+impl AddAssign for Fr {
+    fn add_assign(&mut self, other: Self) { *self = *self + other; }
+}
+
+impl SubAssign for Fr {
+    fn sub_assign(&mut self, other: Self) { *self = *self - other; }
+}
+
+impl MulAssign for Fr {
+    fn mul_assign(&mut self, other: Self) { *self = *self * other; }
+}
+
+impl Display for Fr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result { write!(f, "{}", self.value) }
+}
+
+/// Configuration for the quadratic extension `Fp2 = Fp[u]/(u² + 1)`; `−1` is a
+/// non-residue because `p ≡ 3 (mod 4)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fq2Config;
+
+impl QuadraticExtensionConfig for Fq2Config {
+    type BaseField = Fq;
+
+    fn non_residue() -> Fq { -Fq::one() }
+}
+
+/// The pairing target field `Fp2`.
+pub type Fq2 = QuadraticExtension<Fq2Config>;
+
+/// The example supersingular curve `y² = x³ + x` over `Fq`, restricted to its
+/// prime-order subgroup for the pairing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExampleCurve;
+
+fn curve_a<F: Field>() -> F { F::one() }
+
+// Affine addition for `y² = x³ + a·x` over any field `F`, shared by the curve
+// group law over `Fq` and the Miller-loop arithmetic over `Fq2`.
+fn affine_add<F: Field>(p: &AffinePoint<F>, q: &AffinePoint<F>) -> AffinePoint<F> {
+    if p.infinity {
+        return q.clone();
+    }
+    if q.infinity {
+        return p.clone();
+    }
+    if p.x == q.x && (p.y.clone() + q.y.clone()).is_zero() {
+        return AffinePoint { x: F::zero(), y: F::zero(), infinity: true };
+    }
+
+    let slope = if p.x == q.x {
+        let num = F::one() + F::one() + F::one();
+        let num = num * p.x.square() + curve_a::<F>();
+        let den = (p.y.clone() + p.y.clone()).inverse().expect("2y invertible");
+        num * den
+    } else {
+        let den = (q.x.clone() - p.x.clone()).inverse().expect("x difference invertible");
+        (q.y.clone() - p.y.clone()) * den
+    };
+
+    let x = slope.square() - p.x.clone() - q.x.clone();
+    let y = slope * (p.x.clone() - x.clone()) - p.y.clone();
+    AffinePoint { x, y, infinity: false }
+}
+
+fn affine_scalar_mul<F: Field>(point: &AffinePoint<F>, scalar: &[u64]) -> AffinePoint<F> {
+    let mut result = AffinePoint { x: F::zero(), y: F::zero(), infinity: true };
+    for &limb in scalar.iter().rev() {
+        for i in (0..64).rev() {
+            result = affine_add(&result, &result);
+            if (limb >> i) & 1 == 1 {
+                result = affine_add(&result, point);
+            }
+        }
+    }
+    result
+}
+
+impl EllipticCurve for ExampleCurve {
+    type BaseField = Fq;
+    type Point = AffinePoint<Fq>;
+    type ProjectivePoint = ProjectivePoint<Fq>;
+
+    fn identity() -> Self::Point {
+        AffinePoint { x: Fq::zero(), y: Fq::zero(), infinity: true }
+    }
+
+    fn generator() -> Self::Point {
+        AffinePoint { x: Fq::new(GENERATOR_X), y: Fq::new(GENERATOR_Y), infinity: false }
+    }
+
+    fn order() -> Vec<u64> { vec![GROUP_ORDER] }
+
+    fn add_points(p1: &Self::Point, p2: &Self::Point) -> Self::Point { affine_add(p1, p2) }
+
+    fn scalar_mul(point: &Self::Point, scalar: &[u64]) -> Self::Point {
+        affine_scalar_mul(point, scalar)
+    }
+
+    fn msm(bases: &[Self::Point], scalars: &[&[u64]]) -> Self::Point {
+        // Sums one scalar multiplication per base; no bucket method is used.
+        let mut acc = Self::identity();
+        for (base, scalar) in bases.iter().zip(scalars.iter()) {
+            acc = affine_add(&acc, &affine_scalar_mul(base, scalar));
+        }
+        acc
+    }
+
+    fn is_on_curve(point: &Self::Point) -> bool {
+        if point.infinity {
+            return true;
+        }
+        let lhs = point.y.square();
+        let rhs = point.x.pow(3) + curve_a::<Fq>() * point.x.clone();
+        lhs == rhs
+    }
+}
+
+// Embeds an `Fq` point into `Fq2` (the coordinate on the `u⁰` component).
+fn embed(p: &AffinePoint<Fq>) -> AffinePoint<Fq2> {
+    if p.infinity {
+        return AffinePoint { x: Fq2::zero(), y: Fq2::zero(), infinity: true };
+    }
+    AffinePoint {
+        x:        Fq2::new(p.x, Fq::zero()),
+        y:        Fq2::new(p.y, Fq::zero()),
+        infinity: false,
+    }
+}
+
+// Distortion map φ(x, y) = (−x, u·y), moving a subgroup point to a linearly
+// independent point in `E(Fp2)` so the symmetric pairing is non-degenerate.
+fn distort(p: &AffinePoint<Fq>) -> AffinePoint<Fq2> {
+    if p.infinity {
+        return AffinePoint { x: Fq2::zero(), y: Fq2::zero(), infinity: true };
+    }
+    AffinePoint {
+        x:        Fq2::new(-p.x, Fq::zero()),
+        y:        Fq2::new(Fq::zero(), p.y),
+        infinity: false,
+    }
+}
+
+// Evaluates the Miller line through `t` and `step` at `q`, divided by the
+// vertical line through the sum, i.e. the contribution of one loop iteration.
+fn line_value(
+    t: &AffinePoint<Fq2>,
+    step: &AffinePoint<Fq2>,
+    q: &AffinePoint<Fq2>,
+) -> Fq2 {
+    // A vertical line (the final step adds `(r−1)P` to `P`, reaching infinity)
+    // degenerates to `x − t.x`, with no denominator.
+    if t.x == step.x && (t.y.clone() + step.y.clone()).is_zero() {
+        return q.x.clone() - t.x.clone();
+    }
+
+    let slope = if t == step {
+        let num = (Fq2::one() + Fq2::one() + Fq2::one()) * t.x.square() + curve_a::<Fq2>();
+        let den = (t.y.clone() + t.y.clone()).inverse().expect("2y invertible");
+        num * den
+    } else {
+        let den = (step.x.clone() - t.x.clone()).inverse().expect("x difference invertible");
+        (step.y.clone() - t.y.clone()) * den
+    };
+
+    let sum = affine_add(t, step);
+    let numerator = q.y.clone() - t.y.clone() - slope.clone() * (q.x.clone() - t.x.clone());
+    let denominator = q.x.clone() - sum.x.clone();
+    numerator * denominator.inverse().expect("vertical line invertible")
+}
+
+// The Miller loop accumulating `f_{r,P}(φ(Q))` for a single pair.
+fn miller(p: &AffinePoint<Fq>, q: &AffinePoint<Fq>) -> Fq2 {
+    let p2 = embed(p);
+    let q2 = distort(q);
+
+    let mut f = Fq2::one();
+    let mut t = p2.clone();
+
+    let bits = 64 - GROUP_ORDER.leading_zeros();
+    for i in (0..bits - 1).rev() {
+        f = f.square() * line_value(&t, &t, &q2);
+        t = affine_add(&t, &t);
+        if (GROUP_ORDER >> i) & 1 == 1 {
+            f = f * line_value(&t, &p2, &q2);
+            t = affine_add(&t, &p2);
+        }
+    }
+    f
+}
+
+impl PairingCurve for ExampleCurve {
+    type TargetField = Fq2;
+
+    fn pairing(p: &Self::Point, q: &Self::Point) -> Self::TargetField {
+        miller(p, q).pow(FINAL_EXPONENT)
+    }
+}
+
+/// A pairing engine over the example supersingular curve. Both source groups
+/// are the same prime-order subgroup, tied together by the distortion map, so
+/// the resulting symmetric pairing is suitable for instantiating KZG.
+pub struct ExampleEngine;
+
+impl Engine for ExampleEngine {
+    type G1 = ExampleCurve;
+    type G2 = ExampleCurve;
+    type Fr = Fr;
+    type Fqk = Fq2;
+
+    fn miller_loop<'a, I>(pairs: I) -> Self::Fqk
+    where
+        I: IntoIterator<
+            Item = (
+                &'a <Self::G1 as EllipticCurve>::Point,
+                &'a <Self::G2 as EllipticCurve>::Point,
+            ),
+        >,
+    {
+        let mut acc = Fq2::one();
+        for (p, q) in pairs {
+            acc = acc * miller(p, q);
+        }
+        acc
+    }
+
+    fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk> {
+        if f.is_zero() {
+            None
+        } else {
+            Some(f.pow(FINAL_EXPONENT))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn g_mul(k: u64) -> AffinePoint<Fq> {
+        ExampleCurve::scalar_mul(&ExampleCurve::generator(), &[k])
+    }
+
+    #[test]
+    fn generator_has_prime_order() {
+        let g = ExampleCurve::generator();
+        assert!(ExampleCurve::is_on_curve(&g));
+        assert!(ExampleCurve::scalar_mul(&g, &[GROUP_ORDER]).infinity);
+        assert!(!g_mul(1).infinity);
+    }
+
+    #[test]
+    fn pairing_is_non_degenerate() {
+        let g = ExampleCurve::generator();
+        let e = ExampleEngine::pairing(&g, &g).unwrap();
+        assert_ne!(e, Fq2::one());
+        // The value is an r-th root of unity.
+        assert_eq!(e.pow(GROUP_ORDER), Fq2::one());
+    }
+
+    #[test]
+    fn pairing_is_bilinear() {
+        let g = ExampleCurve::generator();
+        let base = ExampleEngine::pairing(&g, &g).unwrap();
+        for (a, b) in [(2u64, 3u64), (5, 7), (4, 11)] {
+            let lhs = ExampleEngine::pairing(&g_mul(a), &g_mul(b)).unwrap();
+            let rhs = base.pow((a * b) % GROUP_ORDER);
+            assert_eq!(lhs, rhs);
+        }
+    }
+
+    #[test]
+    fn pairing_matches_paircurve_trait() {
+        let g = ExampleCurve::generator();
+        assert_eq!(
+            ExampleEngine::pairing(&g, &g).unwrap(),
+            <ExampleCurve as PairingCurve>::pairing(&g, &g),
+        );
+    }
+}